@@ -59,7 +59,7 @@ fn generate_structs(
     ensure!(status.success());
 
     let mut format_cmd = Command::new("rustfmt");
-    cmd.arg(&out_file);
+    format_cmd.arg(&out_file);
     let status = format_cmd.status()?;
     ensure!(status.success());
 